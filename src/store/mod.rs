@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use pyo3::{exceptions::PyRuntimeError, PyResult};
+use zarrs::storage::storage_adapter::async_to_sync::{AsyncToSyncBlockOn, AsyncToSyncStorageAdapter};
+use zarrs::storage::{AsyncReadableWritableListableStorage, ReadableWritableListableStorage};
+
+use zarrs_object_store::AsyncObjectStore;
+
+use crate::utils::PyErrExt;
+
+/// A handle used to drive `object_store`'s async I/O from the synchronous storage traits
+/// that `zarrs` expects, backed by a dedicated multi-threaded Tokio runtime.
+struct TokioBlockOn(tokio::runtime::Runtime);
+
+impl AsyncToSyncBlockOn for TokioBlockOn {
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.0.block_on(future)
+    }
+}
+
+static TOKIO_RUNTIME: Lazy<Arc<TokioBlockOn>> = Lazy::new(|| {
+    Arc::new(TokioBlockOn(
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create the object_store Tokio runtime"),
+    ))
+});
+
+/// Wrap an `object_store::ObjectStore` in the `zarrs` async storage adapter, without bridging it
+/// to the synchronous traits, so async I/O mode can drive it directly from the Tokio runtime.
+pub(crate) fn object_store_to_async_store<T: object_store::ObjectStore>(
+    store: T,
+) -> AsyncReadableWritableListableStorage {
+    Arc::new(AsyncObjectStore::new(store))
+}
+
+/// Wrap an `object_store::ObjectStore` in the `zarrs` async storage adapter and bridge it to a
+/// synchronous [`ReadableWritableListableStorage`] using a shared background Tokio runtime.
+pub(crate) fn object_store_to_sync_store<T: object_store::ObjectStore>(
+    store: T,
+) -> PyResult<ReadableWritableListableStorage> {
+    Ok(async_to_sync_store(object_store_to_async_store(store)))
+}
+
+/// Wrap an `opendal::Builder` in the matching `zarrs` async storage adapter, without bridging it
+/// to the synchronous traits, so async I/O mode can drive it directly from the Tokio runtime.
+pub(crate) fn opendal_builder_to_async_store<B: opendal::Builder>(
+    builder: B,
+) -> PyResult<AsyncReadableWritableListableStorage> {
+    let operator = opendal::Operator::new(builder)
+        .map_py_err::<PyRuntimeError>()?
+        .finish();
+    Ok(Arc::new(zarrs_opendal::AsyncOpendalStore::new(operator)))
+}
+
+/// Wrap an `opendal::Builder` in the matching `zarrs` storage adapter, yielding a synchronous
+/// [`ReadableWritableListableStorage`].
+pub(crate) fn opendal_builder_to_sync_store<B: opendal::Builder>(
+    builder: B,
+) -> PyResult<ReadableWritableListableStorage> {
+    Ok(async_to_sync_store(opendal_builder_to_async_store(builder)?))
+}
+
+/// Bridge an already-built async store to the synchronous traits `zarrs` expects, using the
+/// shared background Tokio runtime that also backs async I/O mode.
+pub(crate) fn async_to_sync_store(
+    store: AsyncReadableWritableListableStorage,
+) -> ReadableWritableListableStorage {
+    Arc::new(AsyncToSyncStorageAdapter::new(store, TOKIO_RUNTIME.clone()))
+}
+
+/// Run `future` to completion on the shared background Tokio runtime, blocking the calling
+/// thread. Used to drive async I/O mode's chunk fetch/store loop from the otherwise-synchronous
+/// `retrieve_chunks`/`store_chunks` entry points.
+pub(crate) fn block_on_tokio<F: std::future::Future>(future: F) -> F::Output {
+    TOKIO_RUNTIME.0.block_on(future)
+}